@@ -1,22 +1,47 @@
 #[macro_use]
 extern crate clap;
+extern crate atty;
 extern crate doogie;
 extern crate env_logger;
+extern crate glob;
 extern crate howser;
+extern crate libc;
 extern crate toml;
+extern crate unicode_width;
 
 use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
-use doogie::parse_document;
+use doogie::{parse_document, Node, NodeType};
+use glob::{glob_with, MatchOptions};
 use howser::document::Document;
-use howser::errors::{HowserError, HowserResult, ValidationProblem};
-use howser::reporters::{make_cli_report, CLIOption};
+use howser::errors::{HowserError, HowserResult, ValidationProblem, Warning};
 use howser::validator::Validator;
 use std::error::Error;
 use std::fs::File;
 use std::io::prelude::*;
+use std::path::Path;
 use std::str;
 use std::collections::BTreeMap;
 use toml::Value;
+use unicode_width::UnicodeWidthStr;
+
+/// Clean validation: the document(s) conformed to the prescription.
+const EXIT_SUCCESS: i32 = 0;
+/// One or more documents failed validation and problems were reported.
+///
+/// In the fault-isolated batch modes (`--pharmacy`, `--glob`) a missing or
+/// unparseable document is reported as a file-scoped problem rather than
+/// aborting the run, so it too resolves to this code — the IO failure of an
+/// individual batch member is surfaced in the report, not the exit status.
+const EXIT_VALIDATION: i32 = 1;
+/// Usage or argument error (`HowserError::Usage`).
+const EXIT_USAGE: i32 = 2;
+/// A directly-named input file could not be read (`HowserError::IOError`):
+/// the single-pair `validate`/`check`/`scaffold` target, or the pharmacy TOML
+/// itself. A missing member *inside* a batch yields `EXIT_VALIDATION` instead
+/// (see that code).
+const EXIT_IO: i32 = 3;
+/// Prescription-compilation or runtime failure.
+const EXIT_RUNTIME: i32 = 4;
 
 fn main() {
     env_logger::init();
@@ -24,32 +49,51 @@ fn main() {
     let app = make_app();
     let matches = app.get_matches();
 
-    if let Err(e) = run(&matches) {
-        println!("{}", e.description());
-        let mut inner_err = e.cause();
-        while let Some(error) = inner_err {
-            println!("{}", error.description());
-            inner_err = error.cause();
+    let code = match run(&matches) {
+        Ok(false) => EXIT_SUCCESS,
+        Ok(true) => EXIT_VALIDATION,
+        Err(e) => {
+            // Diagnostics go to stderr so that stdout stays reserved for the
+            // validation report (e.g. clean `--format json` output).
+            eprintln!("{}", e.description());
+            let mut inner_err = e.cause();
+            while let Some(error) = inner_err {
+                eprintln!("{}", error.description());
+                inner_err = error.cause();
+            }
+            exit_code(&e)
         }
-        std::process::exit(1);
-    } else {
-        std::process::exit(0);
+    };
+
+    std::process::exit(code);
+}
+
+/// Maps the root `HowserError` variant onto the process exit-code taxonomy.
+fn exit_code(error: &HowserError) -> i32 {
+    match *error {
+        HowserError::Usage(_) => EXIT_USAGE,
+        HowserError::IOError(_) => EXIT_IO,
+        _ => EXIT_RUNTIME,
     }
 }
 
-fn run(args: &ArgMatches) -> HowserResult<()> {
-    let (issues, options) = match args.subcommand() {
+/// Runs the requested subcommand, printing the validation report to stdout.
+///
+/// Returns `Ok(true)` when problems were reported (a non-clean validation),
+/// `Ok(false)` when the run was clean, and `Err` for usage/IO/runtime failures.
+fn run(args: &ArgMatches) -> HowserResult<bool> {
+    let (issues, settings) = match args.subcommand() {
         ("check", Some(sub_m)) => {
-            let options = vec![CLIOption::VerboseMode(sub_m.is_present("verbose"))];
+            let settings = report_settings(sub_m)?;
             let filename = args
                 .value_of("prescription")
                 .ok_or(HowserError::RuntimeError(
                     "Error parsing prescription filename.".to_string()))?;
 
-            (check(filename)?, options)
+            (check(filename)?, settings)
         }
         ("validate", Some(sub_m)) => {
-            let options = vec![CLIOption::VerboseMode(sub_m.is_present("verbose"))];
+            let settings = report_settings(sub_m)?;
             if sub_m.is_present("pharmacy") {
                 let fail_early = sub_m.is_present("fail-early");
                 let filename = args
@@ -63,7 +107,18 @@ fn run(args: &ArgMatches) -> HowserResult<()> {
                     .ok_or(HowserError::RuntimeError(
                         format!("Error parsing pharmacy file {}.", filename)))?;
 
-                (process_pharmacy_file(prescription_pairs, fail_early)?, options)
+                (process_pharmacy_file(prescription_pairs, fail_early)?, settings)
+            } else if sub_m.is_present("glob") {
+                let rx_name = sub_m
+                    .value_of("prescription")
+                    .ok_or(HowserError::RuntimeError(
+                        "Unable to parse the name of the prescription file.".to_string()))?;
+                let pattern = sub_m
+                    .value_of("glob")
+                    .ok_or(HowserError::RuntimeError(
+                        "Unable to parse the glob pattern.".to_string()))?;
+
+                (validate_glob(rx_name, pattern)?, settings)
             } else {
                 let rx_name = sub_m
                     .value_of("prescription")
@@ -74,16 +129,364 @@ fn run(args: &ArgMatches) -> HowserResult<()> {
                     .ok_or(HowserError::RuntimeError(
                         "Unable to parse the name of the document file.".to_string()))?;
 
-                (validate(rx_name, document_name)?, options)
+                (validate(rx_name, document_name)?, settings)
             }
         }
+        ("scaffold", Some(sub_m)) => {
+            let document_name = sub_m
+                .value_of("document")
+                .ok_or(HowserError::RuntimeError(
+                    "Unable to parse the name of the document file.".to_string()))?;
+
+            let rx_source = scaffold(document_name, sub_m.is_present("optional-headings"))?;
+
+            match sub_m.value_of("output") {
+                Some(out_name) => write_file_contents(out_name, &rx_source)?,
+                None => println!("{}", rx_source),
+            }
+
+            return Ok(false);
+        }
         _ => return Err(HowserError::Usage(args.usage().to_string()))
     };
-    let cli_report = make_cli_report(&issues, &options);
+    let report = make_report(&issues, &settings);
 
-    println!("{}", cli_report);
+    println!("{}", report);
 
-    Ok(())
+    Ok(!issues.is_empty())
+}
+
+/// A validation problem paired with the file it originated from.
+///
+/// The library's `Warning` trait only exposes a rendered message via
+/// `print()`, so the binary tracks the originating file itself as each problem
+/// is produced; this is what lets the machine-readable reporters attach a
+/// filename and SARIF location to every result.
+struct ReportedProblem {
+    file: Option<String>,
+    warning: ValidationProblem,
+}
+
+impl ReportedProblem {
+    /// Renders the underlying warning's message at the requested verbosity.
+    fn message(&self, verbose: bool) -> String {
+        self.warning.print(verbose)
+    }
+}
+
+/// Wraps a `warning` with the `file` it was reported against.
+fn reported(file: &str, warning: ValidationProblem) -> ReportedProblem {
+    ReportedProblem {
+        file: Some(file.to_string()),
+        warning,
+    }
+}
+
+/// The severity of a reported problem, recovered from the rendered message
+/// since the `Warning` trait does not expose it structurally.
+#[derive(Clone, Copy)]
+enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    /// The SARIF/JSON spelling of this severity.
+    fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+
+    /// Classifies a rendered message as a warning when it announces itself as
+    /// one, falling back to error (the common case).
+    fn from_message(message: &str) -> Severity {
+        if message.to_lowercase().contains("warning") {
+            Severity::Warning
+        } else {
+            Severity::Error
+        }
+    }
+}
+
+/// Output format for the validation report.
+///
+/// `Human` wraps and colorizes a readable rendering; `Json` emits a flat array
+/// of problems and `Sarif` a SARIF 2.1.0 document so that CI systems can
+/// post-process or inline-annotate failures.
+#[derive(Clone, Copy)]
+enum ReportFormat {
+    Human,
+    Json,
+    Sarif,
+}
+
+/// When the human-readable report should be colorized.
+#[derive(Clone, Copy)]
+enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+/// Presentation options for a single report, gathered from the common
+/// `--verbose`, `--format` and `--color` flags shared by the subcommands.
+struct ReportSettings {
+    verbose: bool,
+    format: ReportFormat,
+    color: ColorChoice,
+}
+
+/// Collects the presentation flags that every reporting subcommand accepts.
+fn report_settings(args: &ArgMatches) -> HowserResult<ReportSettings> {
+    Ok(ReportSettings {
+        verbose: args.is_present("verbose"),
+        format: parse_format(args)?,
+        color: parse_color(args)?,
+    })
+}
+
+/// Parses the `--format` argument into a `ReportFormat`, defaulting to
+/// `Human` when the flag is absent.
+fn parse_format(args: &ArgMatches) -> HowserResult<ReportFormat> {
+    match args.value_of("format") {
+        None | Some("human") => Ok(ReportFormat::Human),
+        Some("json") => Ok(ReportFormat::Json),
+        Some("sarif") => Ok(ReportFormat::Sarif),
+        Some(other) => Err(HowserError::Usage(format!(
+            "Unrecognized report format '{}'. Expected one of human, json, sarif.",
+            other
+        ))),
+    }
+}
+
+/// Parses the `--color` argument into a `ColorChoice`, defaulting to `Auto`
+/// when the flag is absent.
+fn parse_color(args: &ArgMatches) -> HowserResult<ColorChoice> {
+    match args.value_of("color") {
+        None | Some("auto") => Ok(ColorChoice::Auto),
+        Some("always") => Ok(ColorChoice::Always),
+        Some("never") => Ok(ColorChoice::Never),
+        Some(other) => Err(HowserError::Usage(format!(
+            "Unrecognized color choice '{}'. Expected one of auto, always, never.",
+            other
+        ))),
+    }
+}
+
+/// Renders the validation `problems` in the requested format.
+///
+/// `Human` produces a width-wrapped, optionally colorized rendering; `Json`
+/// and `Sarif` serialize each problem's file, severity and message for machine
+/// consumption.
+fn make_report(problems: &Vec<ReportedProblem>, settings: &ReportSettings) -> String {
+    match settings.format {
+        ReportFormat::Human => render_human(problems, settings.verbose, settings.color),
+        ReportFormat::Json => json_report(problems, settings.verbose),
+        ReportFormat::Sarif => sarif_report(problems, settings.verbose),
+    }
+}
+
+const COLOR_ERROR: &str = "\x1b[31m";
+const COLOR_WARNING: &str = "\x1b[33m";
+const COLOR_HEADER: &str = "\x1b[36m";
+const COLOR_RESET: &str = "\x1b[0m";
+
+/// Renders the problems as a human-readable report: one line per problem,
+/// prefixed with its severity and originating file, wrapped to the terminal
+/// width and colorized by severity.
+///
+/// Color is emitted only when enabled by the `--color` choice (and, for
+/// `Auto`, only when stdout is a terminal), so piped output stays plain.
+fn render_human(problems: &Vec<ReportedProblem>, verbose: bool, color: ColorChoice) -> String {
+    let colorize = match color {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => stdout_is_tty(),
+    };
+    let width = terminal_width();
+
+    let mut lines = Vec::new();
+    for problem in problems {
+        let message = problem.message(verbose);
+        let label = match Severity::from_message(&message) {
+            Severity::Error => "Error",
+            Severity::Warning => "Warning",
+        };
+        let line = match problem.file {
+            Some(ref file) => format!("{} [{}]: {}", label, file, message),
+            None => format!("{}: {}", label, message),
+        };
+        for wrapped in wrap_line(&line, width) {
+            if colorize {
+                lines.push(colorize_line(&wrapped));
+            } else {
+                lines.push(wrapped);
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Returns true when stdout is connected to a terminal.
+fn stdout_is_tty() -> bool {
+    atty::is(atty::Stream::Stdout)
+}
+
+/// Queries the terminal width in columns via `ioctl(TIOCGWINSZ)` on stdout,
+/// falling back to 80 when stdout is not a terminal or the query fails.
+fn terminal_width() -> usize {
+    unsafe {
+        let mut winsize: libc::winsize = std::mem::zeroed();
+        if libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut winsize) == 0
+            && winsize.ws_col > 0
+        {
+            winsize.ws_col as usize
+        } else {
+            80
+        }
+    }
+}
+
+/// Colorizes a single report line based on its leading severity/header token:
+/// red for errors, yellow for warnings and cyan for file/location headers.
+fn colorize_line(line: &str) -> String {
+    let trimmed = line.trim_start();
+    let color = if trimmed.starts_with("Error") {
+        Some(COLOR_ERROR)
+    } else if trimmed.starts_with("Warning") {
+        Some(COLOR_WARNING)
+    } else if trimmed.starts_with("File") || trimmed.starts_with("-->") {
+        Some(COLOR_HEADER)
+    } else {
+        None
+    };
+
+    match color {
+        Some(code) => format!("{}{}{}", code, line, COLOR_RESET),
+        None => line.to_string(),
+    }
+}
+
+/// Wraps `line` so that no output row exceeds `width` display columns, breaking
+/// on spaces. A single word wider than `width` is emitted on its own row rather
+/// than split mid-grapheme.
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    if width == 0 || display_width(line) <= width {
+        return vec![line.to_string()];
+    }
+
+    let mut rows = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for word in line.split(' ') {
+        let word_width = display_width(word);
+        if current.is_empty() {
+            current.push_str(word);
+            current_width = word_width;
+        } else if current_width + 1 + word_width <= width {
+            current.push(' ');
+            current.push_str(word);
+            current_width += 1 + word_width;
+        } else {
+            rows.push(current.clone());
+            current.clear();
+            current.push_str(word);
+            current_width = word_width;
+        }
+    }
+
+    if !current.is_empty() {
+        rows.push(current);
+    }
+
+    rows
+}
+
+/// Measures the display width of `s` in terminal columns using the
+/// `unicode-width` tables, so East Asian wide glyphs count as two columns and
+/// zero-width/combining marks as none.
+fn display_width(s: &str) -> usize {
+    UnicodeWidthStr::width(s)
+}
+
+/// Serializes the problems as a flat JSON array of `{file, severity, message}`
+/// objects so that scripts can post-process the failures.
+fn json_report(problems: &Vec<ReportedProblem>, verbose: bool) -> String {
+    let entries: Vec<String> = problems
+        .iter()
+        .map(|problem| {
+            let message = problem.message(verbose);
+            let severity = Severity::from_message(&message);
+            let file = match problem.file {
+                Some(ref file) => format!("\"file\": {}, ", json_string(file)),
+                None => String::new(),
+            };
+            format!(
+                "  {{{}\"severity\": {}, \"message\": {}}}",
+                file,
+                json_string(severity.as_str()),
+                json_string(&message)
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]", entries.join(",\n"))
+}
+
+/// Serializes the problems as a SARIF 2.1.0 `runs[].results[]` document so that
+/// GitHub/GitLab can annotate pull requests inline. When a problem carries a
+/// file it is emitted as a `physicalLocation` so the annotation lands on the
+/// right file.
+fn sarif_report(problems: &Vec<ReportedProblem>, verbose: bool) -> String {
+    let results: Vec<String> = problems
+        .iter()
+        .map(|problem| {
+            let message = problem.message(verbose);
+            let severity = Severity::from_message(&message);
+            let locations = match problem.file {
+                Some(ref file) => format!(
+                    ", \"locations\": [{{\"physicalLocation\": {{\"artifactLocation\": {{\"uri\": {}}}}}}}]",
+                    json_string(file)
+                ),
+                None => String::new(),
+            };
+            format!(
+                "        {{\"level\": {}, \"message\": {{\"text\": {}}}{}}}",
+                json_string(severity.as_str()),
+                json_string(&message),
+                locations
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\n  \"version\": \"2.1.0\",\n  \"$schema\": \"https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json\",\n  \"runs\": [\n    {{\n      \"tool\": {{\"driver\": {{\"name\": \"Howser\", \"version\": {}}}}},\n      \"results\": [\n{}\n      ]\n    }}\n  ]\n}}",
+        json_string(crate_version!()),
+        results.join(",\n")
+    )
+}
+
+/// Escapes `s` as a JSON string literal (including the surrounding quotes).
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
 }
 
 fn make_app<'a, 'b>() -> App<'a, 'b> {
@@ -106,6 +509,24 @@ fn make_app<'a, 'b>() -> App<'a, 'b> {
                         .long("verbose")
                         .help("Use verbose (multiline) output for errors and warnings."),
                 )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .help("Output format for the validation report.")
+                        .value_name("FORMAT")
+                        .takes_value(true)
+                        .possible_values(&["human", "json", "sarif"])
+                        .default_value("human"),
+                )
+                .arg(
+                    Arg::with_name("color")
+                        .long("color")
+                        .help("Controls colorized output.")
+                        .value_name("WHEN")
+                        .takes_value(true)
+                        .possible_values(&["auto", "always", "never"])
+                        .default_value("auto"),
+                )
                 .arg(
                     Arg::with_name("prescription")
                         .required(true)
@@ -127,6 +548,15 @@ fn make_app<'a, 'b>() -> App<'a, 'b> {
                         .value_name("PHARMACY")
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("glob")
+                        .short("-g")
+                        .long("glob")
+                        .help("Validates every file matching the glob pattern against the prescription.")
+                        .value_name("PATTERN")
+                        .takes_value(true)
+                        .conflicts_with("pharmacy"),
+                )
                 .arg(
                     Arg::with_name("fail-early")
                         .short("-e")
@@ -139,61 +569,336 @@ fn make_app<'a, 'b>() -> App<'a, 'b> {
                         .long("verbose")
                         .help("Use verbose (multiline) output for errors and warnings."),
                 )
+                .arg(
+                    Arg::with_name("format")
+                        .long("format")
+                        .help("Output format for the validation report.")
+                        .value_name("FORMAT")
+                        .takes_value(true)
+                        .possible_values(&["human", "json", "sarif"])
+                        .default_value("human"),
+                )
+                .arg(
+                    Arg::with_name("color")
+                        .long("color")
+                        .help("Controls colorized output.")
+                        .value_name("WHEN")
+                        .takes_value(true)
+                        .possible_values(&["auto", "always", "never"])
+                        .default_value("auto"),
+                )
                 .arg(
                     Arg::with_name("prescription")
-                        .required_unless("pharmacy")
+                        .required_unless_one(&["pharmacy", "glob"])
                         .takes_value(true)
                         .value_name("PRESCRIPTION"),
                 )
                 .arg(
                     Arg::with_name("document")
-                        .required_unless("pharmacy")
+                        .required_unless_one(&["pharmacy", "glob"])
+                        .takes_value(true)
+                        .value_name("DOCUMENT"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("scaffold")
+                .about("Generates a starter .rx Prescription from an existing Markdown document.")
+                .help_message("Prints help information.")
+                .setting(AppSettings::ArgRequiredElseHelp)
+                .arg(
+                    Arg::with_name("optional-headings")
+                        .long("optional-headings")
+                        .help("Downgrade heading nodes to optional prompts."),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .short("o")
+                        .long("output")
+                        .help("Write the generated prescription to a file instead of stdout.")
+                        .value_name("FILE")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("document")
+                        .required(true)
+                        .help("Markdown document to scaffold a prescription from.")
                         .takes_value(true)
                         .value_name("DOCUMENT"),
                 ),
         )
 }
 
-fn validate(rx_name: &str, document_name: &str) -> HowserResult<Vec<ValidationProblem>> {
+fn validate(rx_name: &str, document_name: &str) -> HowserResult<Vec<ReportedProblem>> {
     let rx_root = parse_document(&get_file_contents(rx_name)?);
     let doc_root = parse_document(&get_file_contents(document_name)?);
     let rx = Document::new(&rx_root, Some(rx_name.to_string()))?.into_prescription()?;
     let document = Document::new(&doc_root, Some(document_name.to_string()))?;
 
-    Validator::new(rx, document).validate()
+    Ok(Validator::new(rx, document)
+        .validate()?
+        .into_iter()
+        .map(|warning| reported(document_name, warning))
+        .collect())
+}
+
+/// Validates every document matching `pattern` against the single prescription
+/// `rx_name`, aggregating the problems from each matched file.
+///
+/// The pattern is expanded case-insensitively so that `docs/**/*.md` also picks
+/// up `*.MD` files on case-sensitive filesystems.
+fn validate_glob(rx_name: &str, pattern: &str) -> HowserResult<Vec<ReportedProblem>> {
+    let mut report: Vec<ReportedProblem> = Vec::new();
+
+    for doc_name in expand_glob(pattern)? {
+        match validate(rx_name, &doc_name) {
+            Ok(mut problems) => report.append(&mut problems),
+            Err(e) => report.push(file_scoped_problem(&doc_name, &e)),
+        }
+    }
+
+    Ok(report)
+}
+
+/// Resolves a pharmacy spec's document value into the concrete document paths
+/// it refers to.
+///
+/// A value naming an existing file is passed through unchanged (so filenames
+/// containing `[` are not reinterpreted); otherwise a value containing glob
+/// metacharacters is expanded case-insensitively. A literal path that does not
+/// exist is surfaced as a file-scoped problem rather than silently dropped, so
+/// every spec still produces an entry in the report.
+fn resolve_spec_documents(rx_file: &str, pattern: &str) -> Result<Vec<String>, ReportedProblem> {
+    if Path::new(pattern).exists() {
+        Ok(vec![pattern.to_string()])
+    } else if is_glob_pattern(pattern) {
+        match expand_glob(pattern).map_err(|e| file_scoped_problem(rx_file, &e))? {
+            ref docs if docs.is_empty() => Err(file_scoped_problem(
+                rx_file,
+                &HowserError::RuntimeError(format!("Glob pattern '{}' matched no documents.", pattern)),
+            )),
+            docs => Ok(docs),
+        }
+    } else {
+        Err(file_scoped_problem(
+            pattern,
+            &HowserError::RuntimeError(format!("Document '{}' could not be found.", pattern)),
+        ))
+    }
+}
+
+/// Returns true when `pattern` contains glob metacharacters and should be
+/// expanded rather than treated as a literal path.
+fn is_glob_pattern(pattern: &str) -> bool {
+    pattern.contains('*') || pattern.contains('?') || pattern.contains('[')
+}
+
+/// Expands a glob `pattern` into the list of matching file paths, matching
+/// case-insensitively. Returns a `RuntimeError` if the pattern itself is
+/// malformed.
+fn expand_glob(pattern: &str) -> HowserResult<Vec<String>> {
+    let options = MatchOptions {
+        case_sensitive: false,
+        require_literal_separator: false,
+        require_literal_leading_dot: false,
+    };
+    let paths = glob_with(pattern, &options).map_err(|e| {
+        HowserError::RuntimeError(format!("Invalid glob pattern '{}': {}", pattern, e))
+    })?;
+
+    let mut matches = Vec::new();
+    for entry in paths {
+        let path = entry.map_err(|e| {
+            HowserError::RuntimeError(format!("Error reading glob match: {}", e))
+        })?;
+        matches.push(path.to_string_lossy().into_owned());
+    }
+
+    Ok(matches)
+}
+
+/// The Rx prompt marker denoting a mandatory element.
+const MANDATORY_PROMPT: &str = "-!!-";
+/// The Rx prompt marker denoting an optional element.
+const OPTIONAL_PROMPT: &str = "-??-";
+/// The Rx ditto marker that treats a block's literal inline text as free-form.
+const DITTO_PROMPT: &str = "-\"\"-";
+
+/// Generates a starter `.rx` Prescription from the Markdown document at
+/// `document_name`, annotating every block as mandatory by default (headings
+/// become optional prompts when `optional_headings` is set).
+///
+/// The generated source is round-tripped through `Document::into_prescription`
+/// so that the output is guaranteed to pass `howser check` before it is
+/// returned.
+fn scaffold(document_name: &str, optional_headings: bool) -> HowserResult<String> {
+    let doc_root = parse_document(&get_file_contents(document_name)?);
+    let rx_source = scaffold_source(&doc_root, optional_headings)?;
+
+    // Compile the generated template so the output is guaranteed to pass
+    // `howser check`. Use a synthetic name so a round-trip failure reports
+    // locations against the scaffold, not the input document.
+    let rx_root = parse_document(&rx_source);
+    Document::new(&rx_root, Some("<scaffold>".to_string()))?.into_prescription()?;
+
+    Ok(rx_source)
+}
+
+/// Walks the top-level blocks of the parsed document in order and reproduces
+/// each one (heading, paragraph, list, code block, block quote) from its
+/// rendered CommonMark source, prefixing it with the Rx prompt marker that
+/// makes it a required element. Nested blocks (list items, quoted paragraphs)
+/// are not visited: they appear only inside their parent's rendered source and
+/// get no marker of their own. Heading blocks are downgraded to optional
+/// prompts when `optional_headings` is set.
+fn scaffold_source(root: &Node, optional_headings: bool) -> HowserResult<String> {
+    let traverser = root
+        .capabilities
+        .traverse
+        .as_ref()
+        .ok_or_else(|| HowserError::RuntimeError("Document root is not traversable.".to_string()))?;
+
+    let mut blocks = Vec::new();
+    let mut current = traverser.first_child()?;
+    while let Some(node) = current {
+        blocks.push(scaffold_block(&node, optional_headings)?);
+        let sibling = node
+            .capabilities
+            .traverse
+            .as_ref()
+            .ok_or_else(|| HowserError::RuntimeError("Block node is not traversable.".to_string()))?;
+        current = sibling.next_sibling()?;
+    }
+
+    Ok(blocks.join("\n\n"))
 }
 
-fn check(filename: &str) -> HowserResult<Vec<ValidationProblem>> {
+/// Reproduces a single block node as its marked-up Rx source: the prompt
+/// marker, the block's CommonMark, and — for prose blocks whose literal text
+/// should be free-form — an inline ditto marker appended to the content so the
+/// prescription accepts any text in that position rather than a verbatim match.
+fn scaffold_block(node: &Node, optional_headings: bool) -> HowserResult<String> {
+    let getter = node
+        .capabilities
+        .get
+        .as_ref()
+        .ok_or_else(|| HowserError::RuntimeError("Block node is not introspectable.".to_string()))?;
+    let renderer = node
+        .capabilities
+        .render
+        .as_ref()
+        .ok_or_else(|| HowserError::RuntimeError("Block node is not renderable.".to_string()))?;
+
+    let body = renderer.render_commonmark();
+    let body = body.trim_end_matches('\n');
+
+    let (prompt, prose) = match getter.get_type()? {
+        NodeType::Heading => (
+            if optional_headings { OPTIONAL_PROMPT } else { MANDATORY_PROMPT },
+            true,
+        ),
+        NodeType::Paragraph => (MANDATORY_PROMPT, true),
+        _ => (MANDATORY_PROMPT, false),
+    };
+
+    let mut block = format!("{}\n{}", prompt, body);
+    if prose {
+        block.push(' ');
+        block.push_str(DITTO_PROMPT);
+    }
+
+    Ok(block)
+}
+
+fn check(filename: &str) -> HowserResult<Vec<ReportedProblem>> {
     let rx_root = parse_document(&get_file_contents(filename)?);
     let document = Document::new(&rx_root, Some(filename.to_string()))?;
 
     match document.into_prescription() {
-        Err(HowserError::PrescriptionError(warning)) => Ok(vec![Box::new(warning)]),
+        Err(HowserError::PrescriptionError(warning)) => Ok(vec![reported(filename, Box::new(warning))]),
         Err(error) => Err(error),
         Ok(_) => Ok(Vec::new()),
     }
 }
 
-fn process_pharmacy_file(spec_pairs: &BTreeMap<String, Value>, fail_early: bool) -> HowserResult<Vec<ValidationProblem>> {
-    let mut report: Vec<ValidationProblem> = Vec::new();
+fn process_pharmacy_file(spec_pairs: &BTreeMap<String, Value>, fail_early: bool) -> HowserResult<Vec<ReportedProblem>> {
+    // Process specs in their natural (sorted) order so the report is
+    // deterministic and `--fail-early` returns exactly the first failing spec,
+    // matching the sequential baseline's first-in-order guarantee. A spec whose
+    // document value is malformed, missing or unreadable is converted into a
+    // file-scoped problem rather than aborting the whole batch.
+    let mut report: Vec<ReportedProblem> = Vec::new();
 
     for (rx_file, doc_value) in spec_pairs {
-        let doc_file = doc_value
-                .as_str()
-                .ok_or(HowserError::RuntimeError(
-                    "The document corresponding to {} could not be parsed as a string.".to_string()))?;
+        let doc_pattern = match doc_value.as_str() {
+            Some(pattern) => pattern,
+            None => {
+                report.push(file_scoped_problem(rx_file, &HowserError::RuntimeError(format!(
+                    "The document corresponding to {} could not be parsed as a string.", rx_file))));
+                if fail_early {
+                    return Ok(report);
+                }
+                continue;
+            }
+        };
 
-        let mut problems = validate(rx_file, doc_file)?;
-        if fail_early && !problems.is_empty() {
-            return Ok(problems);
-        } else {
-            report.append(&mut problems);
+        let docs = match resolve_spec_documents(rx_file, doc_pattern) {
+            Ok(docs) => docs,
+            Err(problem) => {
+                report.push(problem);
+                if fail_early {
+                    return Ok(report);
+                }
+                continue;
+            }
+        };
+
+        for doc in docs {
+            match validate(rx_file, &doc) {
+                Ok(mut problems) => {
+                    let failed = !problems.is_empty();
+                    report.append(&mut problems);
+                    if fail_early && failed {
+                        return Ok(report);
+                    }
+                }
+                Err(e) => {
+                    report.push(file_scoped_problem(&doc, &e));
+                    if fail_early {
+                        return Ok(report);
+                    }
+                }
+            }
         }
     }
 
     Ok(report)
 }
 
+/// A `Warning` synthesized for a spec- or file-level failure (IO, parse, or
+/// prescription-compilation error) so that a single bad input surfaces as an
+/// entry in the report instead of aborting the batch.
+struct FileScopedWarning {
+    message: String,
+}
+
+impl Warning for FileScopedWarning {
+    fn print(&self, _verbose: bool) -> String {
+        self.message.clone()
+    }
+}
+
+/// Builds a file-scoped `ReportedProblem` from a spec-level error so that a
+/// single bad document surfaces as an entry in the report instead of aborting
+/// the batch.
+fn file_scoped_problem(file: &str, error: &HowserError) -> ReportedProblem {
+    reported(
+        file,
+        Box::new(FileScopedWarning {
+            message: error.description().to_string(),
+        }),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::clap::ErrorKind;
@@ -250,6 +955,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_scaffold_marks_each_block() {
+        let root = super::parse_document("# Title\n\nA paragraph.\n");
+        let rx = super::scaffold_source(&root, false).unwrap();
+        assert!(rx.contains(super::MANDATORY_PROMPT));
+        // The ditto marker is appended inline to the prose content, not left on
+        // a line of its own.
+        assert!(rx.lines().any(|line| {
+            line.ends_with(super::DITTO_PROMPT) && line.trim() != super::DITTO_PROMPT
+        }));
+    }
+
+    #[test]
+    fn test_scaffold_optional_headings_downgrades_headings() {
+        let root = super::parse_document("# Title\n\nA paragraph.\n");
+        let rx = super::scaffold_source(&root, true).unwrap();
+        assert!(rx.contains(super::OPTIONAL_PROMPT));
+    }
+
+    #[test]
+    fn test_json_report_includes_file_and_severity() {
+        let problems = vec![super::file_scoped_problem(
+            "docs/a.md",
+            &super::HowserError::RuntimeError("boom".to_string()),
+        )];
+        let out = super::json_report(&problems, false);
+        assert!(out.contains("\"file\": \"docs/a.md\""));
+        assert!(out.contains("\"severity\": \"error\""));
+    }
+
+    #[test]
+    fn test_sarif_report_includes_location() {
+        let problems = vec![super::file_scoped_problem(
+            "docs/a.md",
+            &super::HowserError::RuntimeError("boom".to_string()),
+        )];
+        let out = super::sarif_report(&problems, false);
+        assert!(out.contains("\"version\": \"2.1.0\""));
+        assert!(out.contains("physicalLocation"));
+        assert!(out.contains("docs/a.md"));
+    }
+
+    #[test]
+    fn test_wrap_line_respects_width() {
+        let wrapped = super::wrap_line("alpha beta gamma delta", 11);
+        assert!(wrapped.iter().all(|row| super::display_width(row) <= 11));
+        assert_eq!(wrapped.join(" "), "alpha beta gamma delta");
+    }
+
+    #[test]
+    fn test_display_width_counts_wide_glyphs() {
+        // A CJK ideograph occupies two columns.
+        assert_eq!(super::display_width("二"), 2);
+        assert_eq!(super::display_width("ab"), 2);
+    }
+
+    #[test]
+    fn test_validate_subcommand_glob_invocation() {
+        let app = super::make_app();
+        let matches = app.get_matches_from(vec![
+            "howser",
+            "validate",
+            "template.rx",
+            "--glob",
+            "docs/**/*.md",
+        ]);
+        let sub_m = matches.subcommand_matches("validate").unwrap();
+        assert_eq!(sub_m.value_of("prescription").unwrap(), "template.rx");
+        assert_eq!(sub_m.value_of("glob").unwrap(), "docs/**/*.md");
+        assert!(sub_m.value_of("document").is_none());
+    }
+
     #[test]
     fn test_check_subcommand() {
         let app = super::make_app();
@@ -287,6 +1064,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_exit_code_taxonomy() {
+        use super::HowserError;
+
+        assert_eq!(super::exit_code(&HowserError::Usage("bad args".to_string())), super::EXIT_USAGE);
+        assert_eq!(
+            super::exit_code(&HowserError::IOError(::std::io::Error::new(
+                ::std::io::ErrorKind::NotFound,
+                "missing",
+            ))),
+            super::EXIT_IO,
+        );
+        assert_eq!(
+            super::exit_code(&HowserError::RuntimeError("boom".to_string())),
+            super::EXIT_RUNTIME,
+        );
+    }
+
 }
 
 /// Returns the textual content of the indicated file
@@ -298,4 +1093,15 @@ fn get_file_contents(file_name: &str) -> HowserResult<String> {
     let mut contents = String::new();
     file.read_to_string(&mut contents)?;
     Ok(contents)
+}
+
+/// Writes `contents` to the indicated file, creating or truncating it.
+///
+/// # Arguments
+/// 'file_name': The name of the file to write.
+/// 'contents': The text to write.
+fn write_file_contents(file_name: &str, contents: &str) -> HowserResult<()> {
+    let mut file = File::create(file_name)?;
+    file.write_all(contents.as_bytes())?;
+    Ok(())
 }
\ No newline at end of file